@@ -0,0 +1,44 @@
+//! Compares the naive linear scan over filters against a reused `Cleaner`, on a url with
+//! 20 query parameters and the default tracker set.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use url_query_cleaner::{clean, untrack, AllowedTracking, Cleaner, MARKETING_TRACKERS};
+
+const ITERATIONS: u32 = 100_000;
+const URL: &str = "https://www.example.com/?utm_source=facebook&utm_medium=social&name=ferret&gclid=abc&color=purple&fbclid=def&size=large&utm_campaign=spring&_ga=1.2.3&page=4&sort=asc&mc_cid=x&lang=en&igshid=y&q=vase&msclkid=z&ref=home&pk_source=w&id=42&yclid=v";
+
+fn measure<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let opts = AllowedTracking::default();
+    let cleaner = Cleaner::from(opts);
+    let prefixes = Cleaner::new(MARKETING_TRACKERS);
+
+    let naive = measure(|| {
+        black_box(untrack(black_box(URL), opts).unwrap());
+    });
+    let optimized = measure(|| {
+        black_box(cleaner.clean(black_box(URL)).unwrap());
+    });
+    let naive_prefixes = measure(|| {
+        black_box(clean(black_box(URL), MARKETING_TRACKERS).unwrap());
+    });
+    let optimized_prefixes = measure(|| {
+        black_box(prefixes.clean(black_box(URL)).unwrap());
+    });
+
+    println!("untrack:                     {:?}/iter", naive);
+    println!("Cleaner::clean:              {:?}/iter", optimized);
+    println!("clean(MARKETING_TRACKERS):   {:?}/iter", naive_prefixes);
+    println!("Cleaner::new(..).clean:      {:?}/iter", optimized_prefixes);
+}
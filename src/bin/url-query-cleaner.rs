@@ -0,0 +1,163 @@
+//! `url-query-cleaner` removes tracking query parameters from urls given as arguments or,
+//! when there are none, read one per line from stdin.
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use url_query_cleaner::{keep_only, untrack, AllowedTracking, CleanError};
+
+const USAGE: &str = "\
+Usage: url-query-cleaner [OPTIONS] [URL]...
+
+Removes tracking query parameters from each URL and prints the result. When no URL is
+given, URLs are read one per line from stdin.
+
+Options:
+    --allow-utm          Keep Urchin Tracking Module parameters (utm_*)
+    --allow-google       Keep Google parameters (gclid, gclsrc, dclid, gbraid, wbraid)
+    --allow-facebook     Keep Facebook parameters (fbclid)
+    --allow-microsoft    Keep Microsoft parameters (msclkid)
+    --allow-zanox        Keep zanox parameters (zanpid)
+    --keep NAME,...      Only keep the given parameters and remove all others
+    --skip-errors        Silently skip malformed URLs instead of failing
+    -h, --help           Print this help";
+
+#[derive(Default)]
+struct Options {
+    allowed: AllowedTracking,
+    keep: Option<Vec<String>>,
+    skip_errors: bool,
+    urls: Vec<String>,
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Options, String> {
+    let mut opts = Options::default();
+    let mut builder = AllowedTracking::builder();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--allow-utm" => builder = builder.allow_utm(),
+            "--allow-google" => builder = builder.allow_google(),
+            "--allow-facebook" => builder = builder.allow_facebook(),
+            "--allow-microsoft" => builder = builder.allow_microsoft(),
+            "--allow-zanox" => builder = builder.allow_zanox(),
+            "--skip-errors" => opts.skip_errors = true,
+            "--keep" => {
+                let names = args
+                    .next()
+                    .ok_or_else(|| "--keep requires a comma separated list".to_string())?;
+                opts.keep = Some(
+                    names
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(String::from)
+                        .collect(),
+                );
+            }
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{}`", flag)),
+            _ => opts.urls.push(arg),
+        }
+    }
+    opts.allowed = builder.build();
+    Ok(opts)
+}
+
+fn clean_one(url: &str, opts: &Options) -> Result<String, CleanError> {
+    opts.keep.as_ref().map_or_else(
+        || untrack(url, opts.allowed),
+        |names| keep_only(url, names.iter().map(String::as_str).collect()),
+    )
+}
+
+fn main() {
+    let opts = match parse_args(env::args().skip(1)) {
+        Ok(opts) => opts,
+        Err(message) => {
+            if message.is_empty() {
+                println!("{}", USAGE);
+                return;
+            }
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let urls: Box<dyn Iterator<Item = io::Result<String>>> = if opts.urls.is_empty() {
+        Box::new(io::stdin().lock().lines())
+    } else {
+        Box::new(opts.urls.clone().into_iter().map(Ok))
+    };
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut failed = false;
+    for url in urls {
+        let url = url.unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        });
+        let url = url.trim();
+        if url.is_empty() {
+            continue;
+        }
+        match clean_one(url, &opts) {
+            Ok(cleaned) => {
+                if writeln!(out, "{}", cleaned).is_err() {
+                    process::exit(1);
+                }
+            }
+            Err(_) if opts.skip_errors => {}
+            Err(_) => {
+                eprintln!("{}", url);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_flags_and_urls() {
+        let opts = args(&["--allow-google", "https://e.com/?gclid=x", "--skip-errors"]).unwrap();
+        assert!(opts.allowed.marketing.gclid);
+        assert!(!opts.allowed.marketing.utm.any());
+        assert!(opts.skip_errors);
+        assert_eq!(opts.urls, vec!["https://e.com/?gclid=x"]);
+        assert_eq!(
+            clean_one("https://e.com/?gclid=x&utm_source=y", &opts).unwrap(),
+            "https://e.com/?gclid=x"
+        );
+    }
+
+    #[test]
+    fn parse_keep() {
+        let opts = args(&["--keep", "name, item"]).unwrap();
+        assert_eq!(
+            opts.keep,
+            Some(vec!["name".to_string(), "item".to_string()])
+        );
+        assert_eq!(
+            clean_one("https://e.com/?name=ferret&color=red&item=vase", &opts).unwrap(),
+            "https://e.com/?name=ferret&item=vase"
+        );
+        assert!(args(&["--keep"]).is_err());
+    }
+
+    #[test]
+    fn parse_unknown_flag() {
+        assert!(args(&["--nope"]).is_err());
+    }
+}
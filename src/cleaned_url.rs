@@ -0,0 +1,102 @@
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{untrack, AllowedTracking, CleanError};
+
+/// `CleanedUrl` is a url that went through `untrack`, so that functions can require one
+/// to be sure the trackers were stripped.
+///
+/// ```rust
+/// use url_query_cleaner::{AllowedTracking, CleanedUrl};
+///
+/// let url = CleanedUrl::untrack(
+///     "https://www.example.com/?utm_source=x&name=ferret",
+///     AllowedTracking::default(),
+/// )
+/// .unwrap();
+/// assert_eq!(&*url, "https://www.example.com/?name=ferret");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CleanedUrl(String);
+
+impl CleanedUrl {
+    /// `untrack` removes the tracking query parameters from `url`, while keeping any set in
+    /// `opts`, as `untrack` does
+    pub fn untrack(url: &str, opts: AllowedTracking) -> Result<Self, CleanError> {
+        untrack(url, opts).map(CleanedUrl)
+    }
+
+    /// `as_str` returns the cleaned url
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// `into_string` returns the cleaned url, consuming the `CleanedUrl`
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+/// Removes every tracking query parameter, like `CleanedUrl::untrack` with no tracking
+/// allowed
+impl TryFrom<&str> for CleanedUrl {
+    type Error = CleanError;
+
+    fn try_from(url: &str) -> Result<Self, Self::Error> {
+        Self::untrack(url, AllowedTracking::default())
+    }
+}
+
+impl Deref for CleanedUrl {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for CleanedUrl {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CleanedUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<CleanedUrl> for String {
+    fn from(url: CleanedUrl) -> Self {
+        url.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn untrack_and_deref() {
+        let url = CleanedUrl::untrack(
+            "https://e.com/?gclid=a&utm_source=b&name=ferret",
+            AllowedTracking::allow_google(),
+        )
+        .unwrap();
+        assert_eq!(&*url, "https://e.com/?gclid=a&name=ferret");
+        assert!(url.ends_with("name=ferret"));
+        assert_eq!(url.to_string(), url.as_str());
+        assert_eq!(String::from(url), "https://e.com/?gclid=a&name=ferret");
+    }
+
+    #[test]
+    fn try_from_str() {
+        let url = CleanedUrl::try_from("https://e.com/?gclid=a&name=ferret").unwrap();
+        assert_eq!(url.as_str(), "https://e.com/?name=ferret");
+        assert_eq!(CleanedUrl::try_from(""), Err(CleanError::EmptyUrl));
+    }
+}
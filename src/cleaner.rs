@@ -0,0 +1,197 @@
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{parse, retain_query, AllowedTracking, AsFilter, CleanError, Filter, FilterMode};
+
+/// `Cleaner` holds a precompiled set of filters so that the same policy can be applied to
+/// many urls without re-deriving it on every call.
+///
+/// ```rust
+/// use url_query_cleaner::{AllowedTracking, Cleaner};
+///
+/// let cleaner = Cleaner::from(AllowedTracking::default());
+/// assert_eq!(
+///     cleaner.clean("https://www.example.com/?utm_source=x&name=ferret").unwrap(),
+///     "https://www.example.com/?name=ferret",
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Cleaner {
+    exact: BTreeSet<String>,
+    // Sorted and with no entry being a prefix of another, so that at most one entry can
+    // match a given name.
+    prefixes: Vec<String>,
+    // Filters that are neither exact nor prefix, or that ignore case, checked linearly.
+    // Case insensitive patterns are stored lowercased.
+    others: Vec<(String, FilterMode, bool)>,
+    // Names that are never matched, even if a filter matches them.
+    exemptions: BTreeSet<String>,
+}
+
+impl Cleaner {
+    /// `new` builds a `Cleaner` that removes parameters whose name starts with any of the
+    /// `filters`, or matches the ones that have their own mode, like `clean` does
+    pub fn new<I, S>(filters: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsFilter,
+    {
+        let mut cleaner = Self::default();
+        for filter in filters {
+            let filter = filter.as_filter(FilterMode::Prefix);
+            cleaner.insert(filter.pattern, filter.mode, filter.ignore_case);
+        }
+        cleaner.compile();
+        cleaner
+    }
+
+    /// `exact` builds a `Cleaner` that removes parameters whose name is exactly one of
+    /// the `filters`, like `clean_exact` does
+    pub fn exact<I, S>(filters: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsFilter,
+    {
+        Self::with_mode(filters, FilterMode::Exact)
+    }
+
+    /// `with_mode` builds a `Cleaner` that removes parameters matching any of the
+    /// `filters` using `mode`, whatever their own, like `clean_with_mode` does
+    pub fn with_mode<I, S>(filters: I, mode: FilterMode) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsFilter,
+    {
+        let mut cleaner = Self::default();
+        for filter in filters {
+            cleaner.insert(filter.as_filter(mode).pattern, mode, false);
+        }
+        cleaner.compile();
+        cleaner
+    }
+
+    /// `from_filters` builds a `Cleaner` honouring the mode of each of the `filters`
+    pub(crate) fn from_filters(filters: &[Filter]) -> Self {
+        let mut cleaner = Self::default();
+        for filter in filters {
+            cleaner.insert(filter.pattern, filter.mode, filter.ignore_case);
+        }
+        cleaner.compile();
+        cleaner
+    }
+
+    /// `insert` adds `pattern` to the structure used for `mode`, skipping empty patterns
+    fn insert(&mut self, pattern: &str, mode: FilterMode, ignore_case: bool) {
+        match mode {
+            _ if pattern.is_empty() => {}
+            _ if ignore_case => self.others.push((pattern.to_lowercase(), mode, true)),
+            FilterMode::Exact => {
+                self.exact.insert(pattern.to_string());
+            }
+            FilterMode::Prefix => self.prefixes.push(pattern.to_string()),
+            mode => self.others.push((pattern.to_string(), mode, false)),
+        }
+    }
+
+    /// `compile` sorts the prefixes and drops the ones made redundant by a shorter one
+    fn compile(&mut self) {
+        self.prefixes.sort();
+        self.prefixes
+            .dedup_by(|longer, shorter| longer.starts_with(shorter.as_str()));
+    }
+
+    /// `is_match` returns true if a parameter called `name` would be removed
+    pub fn is_match(&self, name: &str) -> bool {
+        if self.exemptions.contains(name) {
+            return false;
+        }
+        if self.exact.contains(name) {
+            return true;
+        }
+        let index = self
+            .prefixes
+            .partition_point(|prefix| prefix.as_str() <= name);
+        if index > 0 && name.starts_with(self.prefixes[index - 1].as_str()) {
+            return true;
+        }
+        self.others.iter().any(|(pattern, mode, ignore_case)| {
+            if *ignore_case {
+                mode.matches(&name.to_lowercase(), pattern)
+            } else {
+                mode.matches(name, pattern)
+            }
+        })
+    }
+
+    /// `clean` removes all query parameters matched by this `Cleaner` and returns a new
+    /// simplified url
+    pub fn clean(&self, url: &str) -> Result<String, CleanError> {
+        let mut uri = parse(url)?;
+        retain_query(&mut uri, |name, _| !self.is_match(name));
+        Ok(uri.into())
+    }
+}
+
+impl From<AllowedTracking> for Cleaner {
+    fn from(opts: AllowedTracking) -> Self {
+        let mut cleaner = Self::from_filters(&opts.filters());
+        cleaner.exemptions = opts.exemptions().into_iter().map(String::from).collect();
+        cleaner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn prefix_cleaner_matches_like_clean() {
+        let cleaner = Cleaner::new(vec!["utm_", "name", "namespace", "gclid"]);
+        assert!(cleaner.is_match("utm_source"));
+        assert!(cleaner.is_match("namespace"));
+        assert!(cleaner.is_match("name"));
+        assert!(cleaner.is_match("gclid"));
+        assert!(!cleaner.is_match("nam"));
+        assert!(!cleaner.is_match("utm"));
+        assert!(!cleaner.is_match("item"));
+
+        let url = "https://www.example.com/?utm_source=x&name=ferret&named=y&item=vase";
+        assert_eq!(
+            cleaner.clean(url).unwrap(),
+            crate::clean(url, ["utm_", "name"]).unwrap()
+        );
+    }
+
+    #[test]
+    fn exact_cleaner() {
+        let cleaner = Cleaner::exact(["name"]);
+        assert_eq!(
+            cleaner
+                .clean("https://www.example.com/?name=x&namespace=y")
+                .unwrap(),
+            "https://www.example.com/?namespace=y"
+        );
+    }
+
+    #[test]
+    fn suffix_cleaner() {
+        let cleaner = Cleaner::with_mode(["clid"], FilterMode::Suffix);
+        assert!(cleaner.is_match("gclid"));
+        assert!(!cleaner.is_match("clidx"));
+    }
+
+    #[test]
+    fn cleaner_from_allowed_tracking() {
+        let cleaner = Cleaner::from(AllowedTracking::builder().allow_google().build());
+        assert_eq!(
+            cleaner
+                .clean(
+                    "https://www.example.com/?utm_source=x&gclid=y&fbclid=z&_ga=1&_gac=2&ScCid=3"
+                )
+                .unwrap(),
+            "https://www.example.com/?gclid=y&_gac=2"
+        );
+    }
+}
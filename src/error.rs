@@ -0,0 +1,66 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+
+use url::ParseError;
+
+/// `CleanError` is returned when a url can't be cleaned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CleanError {
+    /// The url could not be parsed
+    Parse(ParseError),
+    /// The url was empty
+    EmptyUrl,
+    /// The url scheme is not one that can be cleaned
+    UnsupportedScheme(String),
+    /// The url has more query parameters than `CleanOptions::max_params` allows
+    TooManyParams,
+    /// The url has this character where RFC 3986 doesn't allow it, which
+    /// `CleanOptions::strict` rejects
+    Invalid(char),
+}
+
+impl fmt::Display for CleanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "invalid url: {}", err),
+            Self::EmptyUrl => f.write_str("empty url"),
+            Self::UnsupportedScheme(scheme) => write!(f, "unsupported scheme `{}`", scheme),
+            Self::TooManyParams => f.write_str("too many query parameters"),
+            Self::Invalid(c) => write!(f, "invalid character {:?} in url", c),
+        }
+    }
+}
+
+impl Error for CleanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// `FilterParseError` is returned when a list of filters can't be parsed into a
+/// `FilterSet`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterParseError {
+    /// The filter at this (zero based) position in the list was empty
+    Empty(usize),
+}
+
+impl fmt::Display for FilterParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty(index) => write!(f, "filter {} is empty", index),
+        }
+    }
+}
+
+impl Error for FilterParseError {}
+
+impl From<ParseError> for CleanError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
@@ -0,0 +1,220 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::slice;
+use core::str::FromStr;
+
+use crate::{clean_with_mode, CleanError, Cleaner, Filter, FilterMode, FilterParseError};
+
+/// `FilterSet` is a list of filters validated so that none of them is empty, as an empty
+/// filter would match every parameter.
+///
+/// A `&FilterSet` can be passed anywhere `clean` and friends take their `filters`. Its
+/// `mode` is honored by the functions that don't take one of their own, like `clean`:
+///
+/// ```rust
+/// use url_query_cleaner::{clean, FilterMode, FilterSet};
+///
+/// let filters = FilterSet::parse("utm_, gclid").unwrap();
+/// let url = "https://www.example.com/?utm_source=x&gclid=y&name=ferret";
+/// assert_eq!(clean(url, &filters).unwrap(), "https://www.example.com/?name=ferret");
+///
+/// let filters = filters.with_mode(FilterMode::Exact);
+/// assert_eq!(
+///     clean(url, &filters).unwrap(),
+///     "https://www.example.com/?utm_source=x&name=ferret",
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterSet {
+    filters: Vec<String>,
+    mode: FilterMode,
+}
+
+impl FilterSet {
+    /// `parse` splits the comma separated `input`, trimming whitespace around each
+    /// filter, and fails if any of them is empty
+    pub fn parse(input: &str) -> Result<Self, FilterParseError> {
+        let filters = input
+            .split(',')
+            .map(str::trim)
+            .enumerate()
+            .map(|(index, filter)| match filter {
+                "" => Err(FilterParseError::Empty(index)),
+                filter => Ok(filter.to_string()),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            filters,
+            mode: FilterMode::default(),
+        })
+    }
+
+    /// `with_mode` sets how the filters are matched, which is by prefix unless set
+    pub const fn with_mode(mut self, mode: FilterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// `mode` returns how the filters are matched
+    pub const fn mode(&self) -> FilterMode {
+        self.mode
+    }
+
+    /// `clean` removes all query parameters that match any of the filters using `mode`,
+    /// like `clean_with_mode` does
+    pub fn clean(&self, url: &str) -> Result<String, CleanError> {
+        clean_with_mode(url, self, self.mode)
+    }
+
+    /// `iter` returns an iterator over the filters, in the order they were given
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.filters.iter().map(String::as_str)
+    }
+
+    /// `len` returns the number of filters
+    pub const fn len(&self) -> usize {
+        self.filters.len()
+    }
+
+    /// `is_empty` returns true if there are no filters
+    pub const fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+}
+
+impl FromStr for FilterSet {
+    type Err = FilterParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+/// Empty filters are dropped, since they would match every parameter, rather than
+/// rejected like `FilterSet::parse` does.
+impl<'a> FromIterator<&'a str> for FilterSet {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(filters: I) -> Self {
+        Self {
+            filters: filters
+                .into_iter()
+                .filter(|filter| !filter.is_empty())
+                .map(str::to_string)
+                .collect(),
+            mode: FilterMode::default(),
+        }
+    }
+}
+
+impl<'a> From<&[&'a str]> for FilterSet {
+    fn from(filters: &[&'a str]) -> Self {
+        filters.iter().copied().collect()
+    }
+}
+
+impl<'a> From<Vec<&'a str>> for FilterSet {
+    fn from(filters: Vec<&'a str>) -> Self {
+        filters.into_iter().collect()
+    }
+}
+
+impl From<&FilterSet> for Cleaner {
+    fn from(filters: &FilterSet) -> Self {
+        Self::with_mode(filters, filters.mode)
+    }
+}
+
+impl<'a> IntoIterator for &'a FilterSet {
+    type Item = Filter<'a>;
+    type IntoIter = FilterSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FilterSetIter {
+            filters: self.filters.iter(),
+            mode: self.mode,
+        }
+    }
+}
+
+/// `FilterSetIter` iterates over the filters of a `FilterSet`, each with the mode of the
+/// set
+#[derive(Debug, Clone)]
+pub struct FilterSetIter<'a> {
+    filters: slice::Iter<'a, String>,
+    mode: FilterMode,
+}
+
+impl<'a> Iterator for FilterSetIter<'a> {
+    type Item = Filter<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mode = self.mode;
+        self.filters.next().map(|filter| Filter::new(filter, mode))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.filters.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn parse_trims_filters() {
+        let filters = FilterSet::parse(" utm_ ,gclid,  fbclid").unwrap();
+        assert_eq!(
+            filters.iter().collect::<Vec<_>>(),
+            vec!["utm_", "gclid", "fbclid"]
+        );
+        assert_eq!("utm_".parse::<FilterSet>().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn build_filter_sets() {
+        let parsed = FilterSet::parse("utm_,gclid").unwrap();
+        let from_vec = FilterSet::from(vec!["utm_", "gclid"]);
+        let from_slice = FilterSet::from(&["utm_", "", "gclid"][..]);
+        let collected: FilterSet = "utm_ gclid".split(' ').collect();
+        assert_eq!(parsed, from_vec);
+        assert_eq!(parsed, from_slice);
+        assert_eq!(parsed, collected);
+
+        let url = "https://e.com/?utm_source=x&gclid=y&gclid_extra=z";
+        assert_eq!(parsed.clean(url).unwrap(), "https://e.com/");
+        let exact = parsed.with_mode(FilterMode::Exact);
+        assert_eq!(exact.mode(), FilterMode::Exact);
+        assert_eq!(
+            exact.clean(url).unwrap(),
+            "https://e.com/?utm_source=x&gclid_extra=z"
+        );
+        assert_eq!(
+            Cleaner::from(&exact).clean(url).unwrap(),
+            exact.clean(url).unwrap()
+        );
+        assert_eq!(
+            crate::clean(url, &exact).unwrap(),
+            exact.clean(url).unwrap()
+        );
+        assert_eq!(
+            crate::clean_with_options(url, &exact, &Default::default()).unwrap(),
+            "https://e.com/?utm_source=x&gclid_extra=z"
+        );
+        assert_eq!(
+            Cleaner::new(&exact).clean(url).unwrap(),
+            exact.clean(url).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_empty_filters() {
+        assert_eq!(FilterSet::parse(""), Err(FilterParseError::Empty(0)));
+        assert_eq!(
+            FilterSet::parse("utm_,,gclid"),
+            Err(FilterParseError::Empty(1))
+        );
+        assert_eq!(FilterSet::parse("utm_, "), Err(FilterParseError::Empty(1)));
+    }
+}
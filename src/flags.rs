@@ -0,0 +1,137 @@
+use core::ops::{BitAnd, BitOr, BitOrAssign, Not};
+
+/// `TrackerFlags` is a compact form of `AllowedTracking`, with one bit per tracking that
+/// is allowed, so that `TrackerFlags::UTM | TrackerFlags::GCLID` allows `utm_*` and
+/// `gclid` only.
+///
+/// The bit of a flag never changes, so `bits` can be stored, e.g. in a database column,
+/// and read back with `from_bits_truncate`.
+///
+/// ```rust
+/// use url_query_cleaner::{untrack_flags, TrackerFlags};
+///
+/// let url = "https://www.example.com/?utm_source=x&gclid=y&fbclid=z";
+/// assert_eq!(
+///     untrack_flags(url, TrackerFlags::UTM | TrackerFlags::GCLID).unwrap(),
+///     "https://www.example.com/?utm_source=x&gclid=y",
+/// );
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TrackerFlags(pub(crate) u64);
+
+impl TrackerFlags {
+    /// Allow every `utm_*` parameter
+    pub const UTM: Self = Self(
+        Self::UTM_SOURCE.0
+            | Self::UTM_MEDIUM.0
+            | Self::UTM_CAMPAIGN.0
+            | Self::UTM_TERM.0
+            | Self::UTM_CONTENT.0,
+    );
+
+    /// `empty` returns the flags allowing no tracking at all
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// `bits` returns the raw value of the flags
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// `from_bits_truncate` builds flags from a raw value, ignoring any unknown bit
+    pub const fn from_bits_truncate(bits: u64) -> Self {
+        Self(bits & Self::all().0)
+    }
+
+    /// `contains` returns true if every flag set in `other` is also set in `self`
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// `is_empty` returns true if no flag is set
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for TrackerFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for TrackerFlags {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitAnd for TrackerFlags {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl Not for TrackerFlags {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_bits_truncate(!self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AllowedTracking;
+
+    #[test]
+    fn round_trip() {
+        for opts in [
+            AllowedTracking::allow_none(),
+            AllowedTracking::allow_google(),
+            AllowedTracking::marketing_only(),
+            AllowedTracking::allow_all(),
+            AllowedTracking::builder()
+                .allow_social()
+                .allow_yandex()
+                .build(),
+        ] {
+            let flags = TrackerFlags::from(opts);
+            assert_eq!(TrackerFlags::from(AllowedTracking::from(flags)), flags);
+            assert_eq!(TrackerFlags::from_bits_truncate(flags.bits()), flags);
+        }
+        assert_eq!(
+            TrackerFlags::from(AllowedTracking::allow_all()),
+            TrackerFlags::all()
+        );
+        assert_eq!(
+            TrackerFlags::from(AllowedTracking::allow_google()),
+            TrackerFlags::GCLID
+                | TrackerFlags::GCLSRC
+                | TrackerFlags::DCLID
+                | TrackerFlags::GBRAID
+                | TrackerFlags::WBRAID
+        );
+        assert!(TrackerFlags::from(AllowedTracking::allow_none()).is_empty());
+    }
+
+    #[test]
+    fn operators() {
+        let flags = TrackerFlags::UTM | TrackerFlags::GA;
+        assert!(flags.contains(TrackerFlags::UTM));
+        assert!(!flags.contains(TrackerFlags::UTM | TrackerFlags::GCLID));
+        assert_eq!(flags & TrackerFlags::GA, TrackerFlags::GA);
+        assert!(!(!flags).contains(TrackerFlags::GA));
+        assert_eq!(!TrackerFlags::all(), TrackerFlags::empty());
+        assert_eq!(
+            TrackerFlags::from_bits_truncate(u64::MAX),
+            TrackerFlags::all()
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use alloc::vec::Vec;
+
+/// `matches` returns true if the whole of `name` matches the glob `pattern`, where `*`
+/// matches any run of characters (including none) and `?` matches exactly one character.
+/// Any other character matches itself.
+pub fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` seen in the pattern and of the name character it was tried
+    // against, so that we can backtrack and let it swallow one more character.
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_is_exact() {
+        assert!(matches("gclid", "gclid"));
+        assert!(!matches("gclid", "gclidx"));
+        assert!(!matches("gclid", "xgclid"));
+    }
+
+    #[test]
+    fn star() {
+        assert!(matches("utm_*", "utm_source"));
+        assert!(matches("utm_*", "utm_"));
+        assert!(!matches("utm_*", "utm"));
+        assert!(matches("*_clid", "ad_clid"));
+        assert!(!matches("*_clid", "ad_clid_x"));
+        assert!(matches("ad*clid", "adclid"));
+        assert!(matches("ad*clid", "ad_xx_clid"));
+        assert!(matches("*", ""));
+        assert!(matches("a*b*c", "a_b_b_c"));
+        assert!(!matches("a*b*c", "a_b_b_d"));
+    }
+
+    #[test]
+    fn question_mark() {
+        assert!(matches("?clid", "gclid"));
+        assert!(!matches("?clid", "clid"));
+        assert!(matches("caf?", "café"));
+    }
+}
@@ -0,0 +1,100 @@
+/// `define_tracker_group` declares a custom group of trackers that works like the built-in
+/// ones.
+///
+/// It generates a struct with a `bool` field per tracker, `true` allowing it, along with a
+/// `filters` method returning the filters of the trackers that aren't allowed.
+///
+/// Each tracker is written as `(field, "pattern", Mode)`, where `Mode` is a `FilterMode`
+/// variant. The filters of several groups, and those of `AllowedTracking::filters`, can be
+/// combined and given to `clean_with_filters`.
+///
+/// ```rust
+/// use url_query_cleaner::{clean_with_filters, define_tracker_group, AllowedTracking};
+///
+/// define_tracker_group! {
+///     /// `InternalTracking` toggles our own tracking parameters
+///     pub struct InternalTracking {
+///         /// Campaign reference (`cref`)
+///         (cref, "cref", Exact),
+///         /// Every `int_*` parameter
+///         (internal, "int_", Prefix),
+///     }
+/// }
+///
+/// let mut filters = AllowedTracking::default().filters();
+/// filters.extend(InternalTracking::default().filters());
+/// assert_eq!(
+///     clean_with_filters("https://e.com/?cref=a&int_x=b&utm_source=c&id=1", &filters).unwrap(),
+///     "https://e.com/?id=1"
+/// );
+/// ```
+#[macro_export]
+macro_rules! define_tracker_group {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$doc:meta])* ($field:ident, $pattern:literal, $mode:ident),)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+        $vis struct $name {
+            $(
+                $(#[$doc])*
+                pub $field: bool,
+            )*
+        }
+
+        impl $name {
+            /// `filters` returns the filters removing the trackers of the group that aren't
+            /// allowed
+            pub fn filters(&self) -> $crate::__private::Vec<$crate::Filter<'static>> {
+                let mut filters = $crate::__private::Vec::new();
+                $(
+                    if !self.$field {
+                        filters.push($crate::Filter::new($pattern, $crate::FilterMode::$mode));
+                    }
+                )*
+                filters
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{clean_with_filters, Filter, FilterMode};
+
+    define_tracker_group! {
+        struct InternalTracking {
+            (cref, "cref", Exact),
+            (internal, "int_", Prefix),
+        }
+    }
+
+    #[test]
+    fn custom_group() {
+        let none = InternalTracking::default();
+        assert_eq!(
+            none.filters(),
+            [
+                Filter::exact("cref"),
+                Filter::new("int_", FilterMode::Prefix)
+            ]
+        );
+        let url = "https://e.com/?cref=a&crefx=b&int_x=c&id=1";
+        assert_eq!(
+            clean_with_filters(url, &none.filters()).unwrap(),
+            "https://e.com/?crefx=b&id=1"
+        );
+
+        let cref = InternalTracking {
+            cref: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            clean_with_filters(url, &cref.filters()).unwrap(),
+            "https://e.com/?cref=a&crefx=b&id=1"
+        );
+    }
+}
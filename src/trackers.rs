@@ -0,0 +1,489 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{AllowedTracking, Filter, TrackerFlags, UntrackReport};
+
+/// `TrackerCategory` is the kind of tracking a built-in tracker does
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TrackerCategory {
+    /// Ad and campaign attribution, like `utm_*` or `gclid`
+    Marketing,
+    /// Site analytics, like `_ga` or the Matomo parameters
+    Analytics,
+    /// Social network click identifiers, like `fbclid` or `igshid`
+    Social,
+    /// Email campaign identifiers, like `mc_cid`
+    Email,
+}
+
+/// `Vendor` is a tracking vendor whose parameters `untrack_allowing` can keep
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Vendor {
+    /// Google click identifiers, like `gclid` or `dclid`
+    Google,
+    /// `fbclid`
+    Facebook,
+    /// `msclkid`
+    Microsoft,
+    /// `zanpid`
+    Zanox,
+    /// `dclid`
+    Doubleclick,
+    /// `utm_*`
+    Urchin,
+}
+
+impl Vendor {
+    /// `flags` returns the `TrackerFlags` that allow the parameters of the vendor
+    pub fn flags(self) -> TrackerFlags {
+        let vendor = match self {
+            Self::Google => "Google",
+            Self::Facebook => "Facebook",
+            Self::Microsoft => "Microsoft",
+            Self::Zanox => "Awin",
+            Self::Doubleclick => return TrackerFlags::DCLID,
+            Self::Urchin => "Urchin",
+        };
+        TrackerFlags::of_vendor(vendor) & AllowedTracking::marketing_only().into()
+    }
+}
+
+/// `TrackerInfo` describes a built-in tracker
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TrackerInfo {
+    /// The vendor behind the tracker, like `"Google"`
+    pub vendor: &'static str,
+    /// The kind of tracking it does
+    pub category: TrackerCategory,
+}
+
+/// `Tracker` is a row of `TRACKERS`
+pub struct Tracker {
+    pub(crate) filter: Filter<'static>,
+    pub(crate) info: TrackerInfo,
+    /// `allowed` returns true if the tracker is allowed, so that `untrack` keeps it
+    pub(crate) allowed: fn(&AllowedTracking) -> bool,
+}
+
+/// `tracker` is a shorthand to build a row of `TRACKERS`
+const fn tracker(
+    filter: Filter<'static>,
+    vendor: &'static str,
+    category: TrackerCategory,
+    allowed: fn(&AllowedTracking) -> bool,
+) -> Tracker {
+    Tracker {
+        filter,
+        info: TrackerInfo { vendor, category },
+        allowed,
+    }
+}
+
+/// `toggle` is the toggle `field` of `AllowedTracking` found under `path`, e.g.
+/// `toggle!(opts, (marketing.utm), source)` is `opts.marketing.utm.source`
+macro_rules! toggle {
+    ($opts:ident, ($($path:ident).+), $field:ident) => {
+        $opts.$($path).+.$field
+    };
+    ($opts:ident, ($($path:ident).+), $field:ident = $value:expr) => {
+        $opts.$($path).+.$field = $value
+    };
+}
+
+/// `trackers` declares every built-in tracker from a single table, so that adding one is a
+/// single line.
+///
+/// Each group becomes a struct found at `path` in `AllowedTracking`, with a `bool` field
+/// per tracker, and each tracker gets a `TrackerFlags` flag and its rows in `TRACKERS`.
+/// `extra` rows go first in `TRACKERS`, and a group can nest other groups `with` its own
+/// trackers.
+macro_rules! trackers {
+    (
+        extra { $($extra:expr,)* }
+        $(
+            $(#[$meta:meta])*
+            $group:ident at $path:tt
+            $(with { $($(#[$nested_meta:meta])* $nested:ident: $nested_ty:ty,)* })?
+            {
+                $(
+                    $(#[$doc:meta])*
+                    $field:ident: $flag:ident = $bit:literal,
+                        $vendor:literal, $category:ident, [$($filter:expr),+ $(,)?];
+                )*
+            }
+        )*
+    ) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+            pub struct $group {
+                $($(
+                    $(#[$nested_meta])*
+                    pub $nested: $nested_ty,
+                )*)?
+                $(
+                    $(#[$doc])*
+                    pub $field: bool,
+                )*
+            }
+
+            impl $group {
+                /// `all` allows every tracking of the group
+                pub const fn all() -> Self {
+                    Self {
+                        $($($nested: <$nested_ty>::all(),)*)?
+                        $($field: true,)*
+                    }
+                }
+
+                /// `none` allows no tracking of the group, which is the same as `Default`
+                pub const fn none() -> Self {
+                    Self {
+                        $($($nested: <$nested_ty>::none(),)*)?
+                        $($field: false,)*
+                    }
+                }
+            }
+        )*
+
+        impl TrackerFlags {
+            $($(
+                $(#[$doc])*
+                pub const $flag: Self = Self(1 << $bit);
+            )*)*
+
+            /// `EACH` lists every single flag, in the order they are declared
+            #[cfg(test)]
+            pub(crate) const EACH: &'static [Self] = &[$($(Self::$flag),*),*];
+
+            /// `all` returns the flags allowing every tracking
+            pub const fn all() -> Self {
+                Self($($(Self::$flag.0)|*)|*)
+            }
+
+            /// `of_vendor` returns the flags allowing the trackers of `vendor`
+            pub(crate) fn of_vendor(vendor: &str) -> Self {
+                let mut flags = Self::empty();
+                $($(
+                    if $vendor == vendor {
+                        flags |= Self::$flag;
+                    }
+                )*)*
+                flags
+            }
+        }
+
+        impl From<AllowedTracking> for TrackerFlags {
+            fn from(opts: AllowedTracking) -> Self {
+                let mut flags = Self::empty();
+                $($(
+                    if toggle!(opts, $path, $field) {
+                        flags |= Self::$flag;
+                    }
+                )*)*
+                flags
+            }
+        }
+
+        impl From<TrackerFlags> for AllowedTracking {
+            fn from(flags: TrackerFlags) -> Self {
+                let mut opts = Self::allow_none();
+                $($(toggle!(opts, $path, $field = flags.contains(TrackerFlags::$flag));)*)*
+                opts
+            }
+        }
+
+        /// `TRACKERS` lists every built-in tracker along with the toggle of `AllowedTracking`
+        /// that allows it. It is the single source of truth for `AllowedTracking::filters`
+        /// and `classify_tracker`.
+        pub static TRACKERS: &[Tracker] = &[
+            $($extra,)*
+            $($($(
+                tracker($filter, $vendor, $category, |o| toggle!(o, $path, $field)),
+            )+)*)*
+        ];
+    };
+}
+
+use TrackerCategory::*;
+
+trackers! {
+    extra {
+        // Every `utm_*` parameter goes unless all the members are allowed. The members that
+        // are allowed are exempt from the prefix, see `AllowedTracking::exemptions`.
+        tracker(Filter::prefix("utm_"), "Urchin", Marketing, |o| {
+            o.marketing.utm == AllowedUtmTracking::all()
+        }),
+    }
+
+    /// `AllowedUtmTracking` allows you to toggle which Urchin Tracking Module parameters to
+    /// be allowed, so that `untrack` doesn't touch them.
+    ///
+    /// Every `utm_*` parameter is removed, including ones that aren't members like `utm_id`,
+    /// except for the members that are allowed. When all of them are allowed every `utm_*`
+    /// parameter is kept.
+    AllowedUtmTracking at (marketing.utm) {
+        /// Campaign source (`utm_source`)
+        source: UTM_SOURCE = 0, "Urchin", Marketing, [Filter::exact("utm_source")];
+        /// Campaign medium (`utm_medium`)
+        medium: UTM_MEDIUM = 1, "Urchin", Marketing, [Filter::exact("utm_medium")];
+        /// Campaign name (`utm_campaign`)
+        campaign: UTM_CAMPAIGN = 2, "Urchin", Marketing, [Filter::exact("utm_campaign")];
+        /// Campaign keyword (`utm_term`)
+        term: UTM_TERM = 3, "Urchin", Marketing, [Filter::exact("utm_term")];
+        /// Campaign content (`utm_content`)
+        content: UTM_CONTENT = 4, "Urchin", Marketing, [Filter::exact("utm_content")];
+    }
+
+    /// `AllowedMarketingTracking` allows you to toggle which marketing tracking to be
+    /// allowed, so that `untrack` doesn't touch it.
+    AllowedMarketingTracking at (marketing) with {
+        /// Urchin Tracking Module - see `AllowedUtmTracking`. With the `serde` feature it can
+        /// also be deserialized from a `bool`, allowing all or none of them.
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::deserialize_utm"))]
+        utm: AllowedUtmTracking,
+    } {
+        /// Google Click Identifier
+        gclid: GCLID = 5, "Google", Marketing, [Filter::prefix("gclid")];
+        /// Google Ads
+        gclsrc: GCLSRC = 6, "Google", Marketing, [Filter::prefix("gclsrc")];
+        /// DoubleClick click identifier, now Google
+        dclid: DCLID = 7, "Google", Marketing, [Filter::prefix("dclid")];
+        /// Google click identifier for iOS app campaigns measured with SKAdNetwork
+        gbraid: GBRAID = 39, "Google", Marketing, [Filter::prefix("gbraid")];
+        /// Google click identifier for iOS web campaigns
+        wbraid: WBRAID = 40, "Google", Marketing, [Filter::prefix("wbraid")];
+        /// Facebook click identifier
+        fbclid: FBCLID = 8, "Facebook", Social, [Filter::prefix("fbclid")];
+        /// Microsoft Bing Ads click identifier
+        msclkid: MSCLKID = 9, "Microsoft", Marketing, [Filter::prefix("msclkid")];
+        /// zanox click identifier, now Awin
+        zanpid: ZANPID = 10, "Awin", Marketing, [Filter::prefix("zanpid")];
+    }
+
+    /// `AllowedAnalyticsTracking` allows you to toggle which analytics tracking to be
+    /// allowed, so that `untrack` doesn't touch it.
+    AllowedAnalyticsTracking at (analytics) {
+        /// Google Analytics cross-domain client identifier (`_ga`)
+        ga: GA = 11, "Google", Analytics, [Filter::exact("_ga")];
+        /// Google Analytics cross-domain linker (`_gl`)
+        gl: GL = 12, "Google", Analytics, [Filter::exact("_gl")];
+    }
+
+    /// `AllowedSocialTracking` allows you to toggle which social network tracking to be
+    /// allowed, so that `untrack` doesn't touch it.
+    AllowedSocialTracking at (social) {
+        /// Instagram share identifier
+        igshid: IGSHID = 13, "Instagram", Social, [Filter::exact("igshid")];
+        /// Twitter/X click identifier
+        twclid: TWCLID = 14, "Twitter", Social, [Filter::exact("twclid")];
+        /// TikTok click identifier
+        ttclid: TTCLID = 15, "TikTok", Social, [Filter::exact("ttclid")];
+        /// LinkedIn first-party ad tracking identifier
+        li_fat_id: LI_FAT_ID = 16, "LinkedIn", Social, [Filter::exact("li_fat_id")];
+        /// Reddit click identifier
+        rdt_cid: RDT_CID = 17, "Reddit", Social, [Filter::exact("rdt_cid")];
+        /// Pinterest click identifier
+        epik: EPIK = 18, "Pinterest", Social, [Filter::exact("epik")];
+        /// Snapchat click identifier (`ScCid`, matched regardless of casing)
+        sccid: SCCID = 19, "Snapchat", Social, [Filter::exact("sccid").ignoring_case()];
+    }
+
+    /// `AllowedEmailTracking` allows you to toggle which email campaign tracking to be
+    /// allowed, so that `untrack` doesn't touch it.
+    AllowedEmailTracking at (email) {
+        /// Mailchimp campaign identifier
+        mc_cid: MC_CID = 20, "Mailchimp", Email, [Filter::exact("mc_cid")];
+        /// Mailchimp email identifier
+        mc_eid: MC_EID = 21, "Mailchimp", Email, [Filter::exact("mc_eid")];
+        /// Marketo tracking token
+        mkt_tok: MKT_TOK = 22, "Marketo", Email, [Filter::exact("mkt_tok")];
+        /// Omeda Olytics anonymous visitor identifier
+        oly_anon_id: OLY_ANON_ID = 35, "Omeda", Email, [Filter::exact("oly_anon_id")];
+        /// Omeda Olytics encrypted customer identifier
+        oly_enc_id: OLY_ENC_ID = 36, "Omeda", Email, [Filter::exact("oly_enc_id")];
+        /// Klaviyo exchange identifier (`_kx`)
+        kx: KX = 37, "Klaviyo", Email, [Filter::exact("_kx")];
+        /// ConvertKit subscriber identifier
+        ck_subscriber_id: CK_SUBSCRIBER_ID = 38, "ConvertKit", Email,
+            [Filter::exact("ck_subscriber_id")];
+    }
+
+    /// `AllowedHubspotTracking` allows you to toggle which HubSpot tracking to be
+    /// allowed, so that `untrack` doesn't touch it.
+    AllowedHubspotTracking at (hubspot) {
+        /// HubSpot encrypted email identifier (`_hsenc`)
+        hsenc: HSENC = 23, "HubSpot", Email, [Filter::exact("_hsenc")];
+        /// HubSpot email identifier (`_hsmi`)
+        hsmi: HSMI = 24, "HubSpot", Email, [Filter::exact("_hsmi")];
+        /// HubSpot session cookie (`__hssc`)
+        hssc: HSSC = 25, "HubSpot", Analytics, [Filter::exact("__hssc")];
+        /// HubSpot visitor cookie (`__hstc`)
+        hstc: HSTC = 26, "HubSpot", Analytics, [Filter::exact("__hstc")];
+        /// HubSpot browser fingerprint (`__hsfp`)
+        hsfp: HSFP = 27, "HubSpot", Analytics, [Filter::exact("__hsfp")];
+    }
+
+    /// `AllowedMatomoTracking` allows you to toggle which Matomo tracking to be
+    /// allowed, so that `untrack` doesn't touch it.
+    AllowedMatomoTracking at (matomo) {
+        /// Legacy Piwik campaign parameters (`pk_campaign`, `pk_kwd`, `pk_source`,
+        /// `pk_medium`, `pk_content` and `pk_cid`)
+        pk: PK = 28, "Matomo", Analytics, [
+            Filter::exact("pk_campaign"),
+            Filter::exact("pk_kwd"),
+            Filter::exact("pk_source"),
+            Filter::exact("pk_medium"),
+            Filter::exact("pk_content"),
+            Filter::exact("pk_cid"),
+        ];
+        /// Matomo Tag Manager campaign parameters (`mtm_*`)
+        mtm: MTM = 29, "Matomo", Analytics, [Filter::prefix("mtm_")];
+    }
+
+    /// `AllowedYandexTracking` allows you to toggle which Yandex tracking to be
+    /// allowed, so that `untrack` doesn't touch it.
+    AllowedYandexTracking at (yandex) {
+        /// Yandex click identifier
+        yclid: YCLID = 30, "Yandex", Marketing, [Filter::exact("yclid")];
+        /// Openstat campaign parameter (`_openstat`)
+        openstat: OPENSTAT = 31, "Openstat", Analytics, [Filter::exact("_openstat")];
+    }
+
+    /// `AllowedEnterpriseTracking` allows you to toggle which enterprise marketing suite
+    /// tracking to be allowed, so that `untrack` doesn't touch it.
+    AllowedEnterpriseTracking at (enterprise) {
+        /// Adobe Analytics campaign identifier
+        s_cid: S_CID = 32, "Adobe", Marketing, [Filter::exact("s_cid")];
+        /// Adobe Advertising click identifier
+        ef_id: EF_ID = 33, "Adobe", Marketing, [Filter::exact("ef_id")];
+        /// Drip subscriber identifier (`__s`)
+        drip: DRIP = 34, "Drip", Email, [Filter::exact("__s")];
+    }
+}
+
+/// `classify_tracker` returns what built-in tracker a query parameter called `name` is,
+/// if any
+///
+/// ```rust
+/// use url_query_cleaner::{classify_tracker, TrackerCategory};
+///
+/// let info = classify_tracker("gclid").unwrap();
+/// assert_eq!((info.vendor, info.category), ("Google", TrackerCategory::Marketing));
+/// assert!(classify_tracker("name").is_none());
+/// ```
+pub fn classify_tracker(name: &str) -> Option<TrackerInfo> {
+    TRACKERS
+        .iter()
+        .find(|tracker| tracker.filter.matches(name))
+        .map(|tracker| tracker.info)
+}
+
+impl UntrackReport {
+    /// `by_category` groups the names of the removed parameters by the category of the
+    /// built-in tracker they are, in the order they were removed. Names that aren't a
+    /// built-in tracker are left out.
+    ///
+    /// It returns a `BTreeMap` rather than a `HashMap`, as `HashMap` needs `std` and this
+    /// stays available without it. The categories are iterated in the order they are
+    /// declared.
+    pub fn by_category(&self) -> BTreeMap<TrackerCategory, Vec<String>> {
+        let mut categories: BTreeMap<TrackerCategory, Vec<String>> = BTreeMap::new();
+        for (name, _) in &self.removed {
+            if let Some(info) = classify_tracker(name) {
+                categories
+                    .entry(info.category)
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+        categories
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::untrack_report;
+    use alloc::vec;
+
+    #[test]
+    fn every_toggle_is_wired() {
+        // Every flag sets a toggle, and every toggle changes what gets removed.
+        let none = AllowedTracking::default();
+        for flag in TrackerFlags::EACH {
+            let opts = AllowedTracking::from(*flag);
+            assert_ne!(opts, none, "{:?} sets no toggle", flag);
+            assert_ne!(opts.filters(), none.filters(), "{:?} has no filter", flag);
+        }
+        assert_eq!(
+            AllowedTracking::from(TrackerFlags::all()),
+            AllowedTracking::allow_all()
+        );
+
+        // Every row is removed under some policy and kept when everything is allowed.
+        for row in TRACKERS {
+            assert!(
+                core::iter::once(&TrackerFlags::empty())
+                    .chain(TrackerFlags::EACH)
+                    .any(|flag| !(row.allowed)(&AllowedTracking::from(*flag))),
+                "{} is never removed",
+                row.filter.pattern
+            );
+            assert!((row.allowed)(&AllowedTracking::allow_all()));
+        }
+    }
+
+    #[test]
+    fn vendor_flags() {
+        assert_eq!(
+            AllowedTracking::from(Vendor::Google.flags()),
+            AllowedTracking::builder().allow_google().build()
+        );
+        assert_eq!(
+            AllowedTracking::from(Vendor::Urchin.flags()),
+            AllowedTracking::builder().allow_utm().build()
+        );
+        assert!(Vendor::Google.flags().contains(Vendor::Doubleclick.flags()));
+    }
+
+    #[test]
+    fn classify() {
+        assert_eq!(
+            classify_tracker("gclid"),
+            Some(TrackerInfo {
+                vendor: "Google",
+                category: Marketing
+            })
+        );
+        assert_eq!(
+            classify_tracker("fbclid"),
+            Some(TrackerInfo {
+                vendor: "Facebook",
+                category: Social
+            })
+        );
+        assert_eq!(classify_tracker("ScCid").unwrap().vendor, "Snapchat");
+        assert_eq!(classify_tracker("utm_id").unwrap().category, Marketing);
+        assert_eq!(classify_tracker("name"), None);
+    }
+
+    #[test]
+    fn report_by_category() {
+        let report = untrack_report(
+            "https://e.com/?gclid=a&fbclid=b&name=ferret&utm_source=c&_ga=d&igshid=e",
+            AllowedTracking::default(),
+        )
+        .unwrap();
+        let categories = report.by_category();
+        assert_eq!(categories[&Marketing], vec!["gclid", "utm_source"]);
+        assert_eq!(categories[&Social], vec!["fbclid", "igshid"]);
+        assert_eq!(categories[&Analytics], vec!["_ga"]);
+        assert!(!categories.contains_key(&Email));
+    }
+}